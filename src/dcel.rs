@@ -0,0 +1,53 @@
+use point::Point;
+use segment::{EdgeType, Site};
+
+/// One directed half of an edge in the DCEL.
+#[derive(Debug, Clone, Copy)]
+pub struct HalfEdge {
+    /// Index into `DCEL::vertices` of the vertex this half-edge starts at.
+    pub origin: usize,
+    pub twin: usize,
+    pub next: usize,
+    pub prev: usize,
+    pub face: usize,
+    pub edge_type: EdgeType,
+}
+
+/// A face of the DCEL: one Voronoi cell, or the single face representing
+/// the outside of the diagram.
+#[derive(Debug, Clone)]
+pub struct Face {
+    pub outer_component: usize,
+    pub alive: bool,
+    /// The input site that generated this cell. Unused (and meaningless)
+    /// for the outside face.
+    pub site: Site,
+    /// Whether this is the single stitched-together face representing the
+    /// outside of the diagram, rather than a real input site's cell.
+    pub is_outside: bool,
+}
+
+/// The Doubly Connected Edge List backing a `VoronoiDiagram`.
+#[derive(Debug)]
+pub struct DCEL {
+    pub vertices: Vec<Point>,
+    pub halfedges: Vec<HalfEdge>,
+    pub faces: Vec<Face>,
+}
+
+impl Default for DCEL {
+    fn default() -> DCEL {
+        DCEL::new()
+    }
+}
+
+impl DCEL {
+    pub fn new() -> DCEL {
+        DCEL { vertices: vec![], halfedges: vec![], faces: vec![] }
+    }
+
+    /// Returns the point this half-edge starts at.
+    pub fn get_origin(&self, edge: usize) -> Point {
+        self.vertices[self.halfedges[edge].origin]
+    }
+}
@@ -2,6 +2,9 @@ use std::slice;
 
 use point::Point;
 use dcel::{DCEL, Face};
+use geometry::{clip_edge, distance, intersect_horizontal, intersect_vertical};
+use rect::Rect;
+use segment::{EdgeType, Segment, Site};
 
 /// Represents a generated Voronoi diagram
 #[derive(Debug)]
@@ -14,42 +17,206 @@ pub struct VoronoiDiagram {
 }
 
 impl VoronoiDiagram {
-    /// Constructs a VoronoiDiagram from a Doubly Connected Edge List
-    pub fn from_dcel(dcel: DCEL) -> VoronoiDiagram {
-        // Work out outside_face_id by finding the face with the most edges
-        // FIXME: This feels hacky and there might be cases where this gets the wrong face
-        let mut highest_edges_count = 0;
-        let mut highest_edges_face = 0;
-        for (i, face) in dcel.faces.iter().enumerate() {
-            if !face.alive { continue; }
-            let mut num_edges = 0;
-            let start_edge = face.outer_component;
-            let mut current_edge = start_edge;
-            loop {
-                num_edges += 1;
-                current_edge = dcel.halfedges[current_edge].next;
-                if current_edge == start_edge { break; }
-            }
-
-            if num_edges > highest_edges_count {
-                highest_edges_count = num_edges;
-                highest_edges_face = i;
-            }
-        }
+    /// Constructs a VoronoiDiagram from a Doubly Connected Edge List.
+    pub fn from_dcel(mut dcel: DCEL) -> VoronoiDiagram {
+        seal(&mut dcel);
+        let outside_face_id = find_outside_face(&dcel);
 
         VoronoiDiagram {
-            dcel: dcel,
-            outside_face_id: highest_edges_face,
+            dcel,
+            outside_face_id,
         }
     }
 
     /// Returns an iterator over the cells in the diagram
     pub fn cells<'a>(&'a self) -> VoronoiCellsIterator<'a> {
         VoronoiCellsIterator {
-            diagram: &self,
+            diagram: self,
             faces_iter: self.dcel.faces.iter().enumerate(),
         }
     }
+
+    /// Returns the cell generated by `sites[site_index]` (in the order
+    /// originally passed to `voronoi()`/`voronoi_with_sites()`), or `None`
+    /// if that site's cell clipped away to nothing.
+    ///
+    /// Cell `i` is always face `i` in the DCEL's construction order, so
+    /// this is a direct lookup rather than a search through `cells()` -
+    /// useful for callers (like Lloyd's relaxation) that need to keep
+    /// every result lined up against its original site, degenerate cells
+    /// included.
+    pub fn cell_for_site(&self, site_index: usize) -> Option<VoronoiCell<'_>> {
+        let face = self.dcel.faces.get(site_index)?;
+        if face.alive { Some(VoronoiCell { dcel: &self.dcel, face_id: site_index }) } else { None }
+    }
+
+    /// Returns the cell whose site is closest to `p`.
+    ///
+    /// A Voronoi cell is, by definition, the locus of points closer to its
+    /// site than to any other, so the cell containing `p` is just the one
+    /// whose site is nearest. This answers the query with a nearest-site
+    /// search (each site's own `distance_squared`, not a point
+    /// approximation of it) rather than testing point-in-polygon
+    /// membership against every cell in turn.
+    pub fn cell_at<'a>(&'a self, p: Point) -> Option<VoronoiCell<'a>> {
+        self.cells().min_by(|a, b| {
+            a.distance_squared_to(p).partial_cmp(&b.distance_squared_to(p)).unwrap()
+        })
+    }
+
+    /// Returns the dual Delaunay triangulation, as a list of triangles
+    /// naming three site indices each (indices into `cells()`'s order).
+    ///
+    /// Two sites are Delaunay-connected exactly when their Voronoi cells
+    /// share a half-edge, and a Voronoi vertex dualizes into a fan of
+    /// triangles around the sites of its incident faces, in their
+    /// rotational order. This just walks the existing DCEL's
+    /// `origin`/`next`/`twin` links; it runs no separate geometry pass.
+    ///
+    /// This assumes generic-position input: a Voronoi vertex shared by
+    /// four or more cocircular sites (e.g. points on a perfect grid) is
+    /// fan-triangulated from its first incident site, which is a valid
+    /// triangulation of the surrounding polygon but not the only one.
+    pub fn delaunay(&self) -> Vec<[usize; 3]> {
+        let mut face_to_site = vec![None; self.dcel.faces.len()];
+        for (site_index, cell) in self.cells().enumerate() {
+            face_to_site[cell.face_id] = Some(site_index);
+        }
+
+        let mut visited = vec![false; self.dcel.vertices.len()];
+        let mut triangles = vec![];
+
+        for edge_id in 0..self.dcel.halfedges.len() {
+            let vertex = self.dcel.halfedges[edge_id].origin;
+            if visited[vertex] { continue; }
+            visited[vertex] = true;
+
+            let sites: Vec<usize> = edges_around_vertex(&self.dcel, edge_id).iter()
+                .filter_map(|&e| face_to_site[self.dcel.halfedges[e].face])
+                .collect();
+
+            for k in 1..sites.len().saturating_sub(1) {
+                triangles.push([sites[0], sites[k], sites[k + 1]]);
+            }
+        }
+
+        triangles
+    }
+}
+
+/// Returns the half-edges leaving `start_edge`'s origin vertex, in
+/// rotational order, by walking `twin(prev(e))` until back to the start.
+fn edges_around_vertex(dcel: &DCEL, start_edge: usize) -> Vec<usize> {
+    let mut edges = vec![start_edge];
+    let mut edge = dcel.halfedges[dcel.halfedges[start_edge].prev].twin;
+    while edge != start_edge {
+        edges.push(edge);
+        edge = dcel.halfedges[dcel.halfedges[edge].prev].twin;
+    }
+    edges
+}
+
+/// Vertices closer together than this are treated as the same point.
+const SEAL_EPSILON: f64 = 1e-9;
+
+/// Cleans up a freshly built DCEL before it's handed to `VoronoiDiagram`:
+/// merges vertices that collapsed to (almost) the same point, and removes
+/// the resulting zero-length half-edge pairs, relinking `next`/`prev`
+/// around them. Collinear or coincident input sites can otherwise leave
+/// near-duplicate vertices and degenerate edges that break cell
+/// traversal.
+fn seal(dcel: &mut DCEL) {
+    merge_close_vertices(dcel, SEAL_EPSILON);
+    remove_zero_length_edges(dcel, SEAL_EPSILON);
+}
+
+fn merge_close_vertices(dcel: &mut DCEL, epsilon: f64) {
+    let n = dcel.vertices.len();
+    let mut canonical: Vec<usize> = (0..n).collect();
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if canonical[j] != j { continue; }
+            if distance(dcel.vertices[i], dcel.vertices[j]) < epsilon {
+                canonical[j] = canonical[i];
+            }
+        }
+    }
+
+    for halfedge in dcel.halfedges.iter_mut() {
+        halfedge.origin = canonical[halfedge.origin];
+    }
+}
+
+fn remove_zero_length_edges(dcel: &mut DCEL, epsilon: f64) {
+    let zero_length: Vec<usize> = (0..dcel.halfedges.len())
+        .filter(|&edge_id| {
+            let he = &dcel.halfedges[edge_id];
+            let origin = dcel.vertices[he.origin];
+            let destination = dcel.vertices[dcel.halfedges[he.next].origin];
+            distance(origin, destination) < epsilon
+        })
+        .collect();
+
+    for edge_id in zero_length {
+        let (prev, next, twin, face) = {
+            let he = &dcel.halfedges[edge_id];
+            (he.prev, he.next, he.twin, he.face)
+        };
+        let (twin_prev, twin_next, twin_face) = {
+            let twin_he = &dcel.halfedges[twin];
+            (twin_he.prev, twin_he.next, twin_he.face)
+        };
+
+        dcel.halfedges[prev].next = next;
+        dcel.halfedges[next].prev = prev;
+        dcel.halfedges[twin_prev].next = twin_next;
+        dcel.halfedges[twin_next].prev = twin_prev;
+
+        if dcel.faces[face].outer_component == edge_id {
+            dcel.faces[face].outer_component = next;
+        }
+        if dcel.faces[twin_face].outer_component == twin {
+            dcel.faces[twin_face].outer_component = twin_next;
+        }
+    }
+}
+
+/// Finds the face representing the outside of the diagram.
+///
+/// `build_dcel` stitches the outside face together explicitly from every
+/// box-boundary edge left over once every cell is clipped, and marks it
+/// with `Face::is_outside`, so this is a direct lookup rather than a
+/// geometric guess. A perimeter-coverage heuristic was tried here before,
+/// but it's ambiguous whenever a real cell *also* spans the whole box
+/// (e.g. a single-site diagram): that cell's edges sum to the full
+/// perimeter too, same as the real outside face. Falls back to the old
+/// most-edges heuristic if no face is marked (e.g. an empty diagram).
+fn find_outside_face(dcel: &DCEL) -> usize {
+    dcel.faces.iter().position(|face| face.is_outside).unwrap_or_else(|| find_face_with_most_edges(dcel))
+}
+
+fn find_face_with_most_edges(dcel: &DCEL) -> usize {
+    let mut highest_edges_count = 0;
+    let mut highest_edges_face = 0;
+    for (i, face) in dcel.faces.iter().enumerate() {
+        if !face.alive { continue; }
+        let mut num_edges = 0;
+        let start_edge = face.outer_component;
+        let mut current_edge = start_edge;
+        loop {
+            num_edges += 1;
+            current_edge = dcel.halfedges[current_edge].next;
+            if current_edge == start_edge { break; }
+        }
+
+        if num_edges > highest_edges_count {
+            highest_edges_count = num_edges;
+            highest_edges_face = i;
+        }
+    }
+
+    highest_edges_face
 }
 
 /// Represents a cell in a Voronoi diagram
@@ -60,6 +227,25 @@ pub struct VoronoiCell<'a> {
 }
 
 impl<'a> VoronoiCell<'a> {
+    /// Returns the input site that generated this cell, projected to a
+    /// point (a segment site projects to its start point). This is a
+    /// representative point for display, not a distance proxy - use
+    /// `distance_squared_to` for nearest-feature queries, since a
+    /// segment's start is generally not its closest point to anywhere.
+    pub fn site(&self) -> Point {
+        match self.dcel.faces[self.face_id].site {
+            Site::Point(p) => p,
+            Site::Segment(s) => s.start,
+        }
+    }
+
+    /// Returns the squared distance from `p` to this cell's site: for a
+    /// segment site, this is the segment's own clamped distance, not the
+    /// distance to the representative point `site()` returns.
+    pub fn distance_squared_to(&self, p: Point) -> f64 {
+        self.dcel.faces[self.face_id].site.distance_squared(p)
+    }
+
     /// Returns a list of points that represent the border of this cell
     pub fn points(&self) -> Vec<Point> {
         let face = &self.dcel.faces[self.face_id];
@@ -76,6 +262,37 @@ impl<'a> VoronoiCell<'a> {
         points
     }
 
+    /// Like `points()`, but approximates any parabolic edges with a
+    /// polyline whose chord never deviates from the true parabola by more
+    /// than `max_dist`.
+    ///
+    /// A parabolic edge borders a point site (its focus) and a segment
+    /// site (its directrix). Each one is sampled by recursively bisecting
+    /// its parameter range and projecting the midpoint onto the parabola,
+    /// stopping once the chord is within `max_dist` of the true curve.
+    pub fn points_discretized(&self, max_dist: f64) -> Vec<Point> {
+        let face = &self.dcel.faces[self.face_id];
+        let mut points = vec![];
+
+        let start_edge = face.outer_component;
+        let mut current_edge = start_edge;
+        loop {
+            let origin = self.dcel.get_origin(current_edge);
+            points.push(origin);
+
+            let next_edge = self.dcel.halfedges[current_edge].next;
+            if let EdgeType::Parabolic { focus, directrix } = self.dcel.halfedges[current_edge].edge_type {
+                let destination = self.dcel.get_origin(next_edge);
+                discretize_parabola(focus, directrix, origin, destination, max_dist, &mut points);
+            }
+
+            current_edge = next_edge;
+            if current_edge == start_edge { break; }
+        }
+
+        points
+    }
+
     /// Calculates the centroid of the cell
     pub fn centroid(&self) -> Point {
         let points = self.points();
@@ -86,6 +303,145 @@ impl<'a> VoronoiCell<'a> {
         }
         sum * (1.0 / (points.len() as f64))
     }
+
+    /// Like `centroid()`, but averages the cell's polygon after clipping
+    /// it to `rect`.
+    pub fn centroid_clipped(&self, rect: &Rect) -> Point {
+        let points = self.clipped(rect);
+        if points.len() < 3 {
+            return self.centroid();
+        }
+
+        let mut sum = Point::new(0.0, 0.0);
+        for &pt in &points {
+            sum = pt + sum;
+        }
+        sum * (1.0 / (points.len() as f64))
+    }
+
+    /// Returns the area of the cell, via the shoelace formula over
+    /// `points()`.
+    pub fn area(&self) -> f64 {
+        shoelace_area(&self.points()).abs()
+    }
+
+    /// Like `area()`, but over the cell's polygon after clipping it to
+    /// `rect`.
+    pub fn area_clipped(&self, rect: &Rect) -> f64 {
+        shoelace_area(&self.clipped(rect)).abs()
+    }
+
+    /// Intersects this cell's polygon against `rect`, using
+    /// Sutherland-Hodgman clipping against each of the rect's four edges
+    /// in turn.
+    pub fn clipped(&self, rect: &Rect) -> Vec<Point> {
+        let mut output = self.points();
+
+        output = clip_edge(&output, |p| p.x > rect.left, |a, b| intersect_vertical(a, b, rect.left.into_inner()));
+        output = clip_edge(&output, |p| p.x < rect.right, |a, b| intersect_vertical(a, b, rect.right.into_inner()));
+        output = clip_edge(&output, |p| p.y > rect.top, |a, b| intersect_horizontal(a, b, rect.top.into_inner()));
+        output = clip_edge(&output, |p| p.y < rect.bottom, |a, b| intersect_horizontal(a, b, rect.bottom.into_inner()));
+
+        output
+    }
+}
+
+/// Computes the signed area of a polygon via the shoelace formula.
+fn shoelace_area(points: &[Point]) -> f64 {
+    let n = points.len();
+    if n < 3 { return 0.0; }
+
+    let mut sum = 0.0;
+    for i in 0..n {
+        let a = points[i];
+        let b = points[(i + 1) % n];
+        sum += a.x.into_inner() * b.y.into_inner() - b.x.into_inner() * a.y.into_inner();
+    }
+    sum / 2.0
+}
+
+/// Appends samples of the parabolic arc between `start` and `end` to
+/// `out`, not including the endpoints themselves. `focus` is the point
+/// site and `directrix` the segment site that bound the arc.
+fn discretize_parabola(focus: Point, directrix: Segment, start: Point, end: Point, max_dist: f64, out: &mut Vec<Point>) {
+    let frame = parabola_frame(directrix, focus);
+    let x_start = parabola_param(frame, start);
+    let x_end = parabola_param(frame, end);
+    subdivide_parabola(frame, x_start, x_end, start, end, max_dist, out);
+}
+
+fn subdivide_parabola(frame: ParabolaFrame, x_lo: f64, x_hi: f64, p_lo: Point, p_hi: Point, max_dist: f64, out: &mut Vec<Point>) {
+    let x_mid = (x_lo + x_hi) / 2.0;
+    let p_mid = parabola_point(frame, x_mid);
+
+    let chord_mid = Point::new(
+        (p_lo.x.into_inner() + p_hi.x.into_inner()) / 2.0,
+        (p_lo.y.into_inner() + p_hi.y.into_inner()) / 2.0,
+    );
+    let error = distance(p_mid, chord_mid);
+
+    if error <= max_dist {
+        return;
+    }
+
+    subdivide_parabola(frame, x_lo, x_mid, p_lo, p_mid, max_dist, out);
+    out.push(p_mid);
+    subdivide_parabola(frame, x_mid, x_hi, p_mid, p_hi, max_dist, out);
+}
+
+/// A local frame for a parabola with a given focus and directrix: the
+/// directrix's start point `origin`, its unit tangent `u` and unit normal
+/// `n` (oriented towards the focus), the perpendicular focus-directrix
+/// distance `h`, and the focus's own coordinate `s_focus` along `u`.
+#[derive(Debug, Clone, Copy)]
+struct ParabolaFrame {
+    origin: Point,
+    u: (f64, f64),
+    n: (f64, f64),
+    h: f64,
+    s_focus: f64,
+}
+
+/// Projects `point` onto the directrix, returning its signed distance
+/// along the directrix from the foot of the focus's perpendicular.
+fn parabola_param(frame: ParabolaFrame, point: Point) -> f64 {
+    let dx = point.x.into_inner() - frame.origin.x.into_inner();
+    let dy = point.y.into_inner() - frame.origin.y.into_inner();
+    (dx * frame.u.0 + dy * frame.u.1) - frame.s_focus
+}
+
+/// Returns the point on the parabola at local coordinate `x` along the
+/// directrix, measured from the foot of the focus's perpendicular.
+fn parabola_point(frame: ParabolaFrame, x: f64) -> Point {
+    let s = x + frame.s_focus;
+    let y = (x * x + frame.h * frame.h) / (2.0 * frame.h);
+
+    Point::new(
+        frame.origin.x.into_inner() + s * frame.u.0 + y * frame.n.0,
+        frame.origin.y.into_inner() + s * frame.u.1 + y * frame.n.1,
+    )
+}
+
+/// Builds the local (origin, tangent, normal) frame for a parabola with
+/// the given directrix and focus.
+fn parabola_frame(directrix: Segment, focus: Point) -> ParabolaFrame {
+    let origin = directrix.start;
+    let dx = directrix.end.x.into_inner() - origin.x.into_inner();
+    let dy = directrix.end.y.into_inner() - origin.y.into_inner();
+    let len = (dx * dx + dy * dy).sqrt();
+    let u = (dx / len, dy / len);
+    let mut n = (-u.1, u.0);
+
+    let fx = focus.x.into_inner() - origin.x.into_inner();
+    let fy = focus.y.into_inner() - origin.y.into_inner();
+    let mut h = fx * n.0 + fy * n.1;
+    if h < 0.0 {
+        n = (-n.0, -n.1);
+        h = -h;
+    }
+    let s_focus = fx * u.0 + fy * u.1;
+
+    ParabolaFrame { origin, u, n, h, s_focus }
 }
 
 #[derive(Debug)]
@@ -98,7 +454,7 @@ impl<'a> Iterator for VoronoiCellsIterator<'a> {
     type Item = VoronoiCell<'a>;
 
     fn next(&mut self) -> Option<VoronoiCell<'a>> {
-        while let Some((i, face)) = self.faces_iter.next() {
+        for (i, face) in self.faces_iter.by_ref() {
             if face.alive && i != self.diagram.outside_face_id {
                 return Some(VoronoiCell {
                     dcel: &self.diagram.dcel,
@@ -113,10 +469,58 @@ impl<'a> Iterator for VoronoiCellsIterator<'a> {
 
 #[cfg(test)]
 mod tests {
-    use voronoi::voronoi;
+    use ordered_float::OrderedFloat;
+
+    use voronoi::{voronoi, voronoi_with_sites};
 
     use super::*;
 
+    #[test]
+    fn test_discretize_parabola_stays_within_max_dist() {
+        let focus = Point::new(0.0, 1.0);
+        let directrix = Segment::new(Point::new(-5.0, 0.0), Point::new(5.0, 0.0));
+        let frame = parabola_frame(directrix, focus);
+
+        let start = parabola_point(frame, -3.0);
+        let end = parabola_point(frame, 3.0);
+
+        let mut points = vec![start];
+        discretize_parabola(focus, directrix, start, end, 0.01, &mut points);
+        points.push(end);
+
+        for window in points.windows(2) {
+            let mid = Point::new(
+                (window[0].x.into_inner() + window[1].x.into_inner()) / 2.0,
+                (window[0].y.into_inner() + window[1].y.into_inner()) / 2.0,
+            );
+            let x_mid = parabola_param(frame, mid);
+            let true_point = parabola_point(frame, x_mid);
+            assert!(distance(true_point, mid) <= 0.01 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_shoelace_area_unit_square() {
+        let square = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 1.0),
+        ];
+        assert_eq!(shoelace_area(&square).abs(), 1.0);
+    }
+
+    #[test]
+    fn test_clip_edge_against_vertical_line() {
+        let triangle = vec![
+            Point::new(-1.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        let clipped = clip_edge(&triangle, |p| p.x > OrderedFloat(0.0), |a, b| intersect_vertical(a, b, 0.0));
+        assert!(clipped.iter().all(|p| p.x.into_inner() >= 0.0));
+    }
+
     #[test]
     fn test_cells_iterator() {
         let vor_pts = vec![Point::new(0.0, 1.0), Point::new(2.0, 3.0), Point::new(10.0, 12.0)];
@@ -128,6 +532,135 @@ mod tests {
     fn test_cells_points() {
         let vor_pts = vec![Point::new(0.0, 1.0), Point::new(2.0, 3.0), Point::new(10.0, 12.0)];
         let vor_diagram = voronoi(vor_pts, 800.);
-        assert_eq!(vor_diagram.cells().nth(0).unwrap().points().len(), 5);
+        assert_eq!(vor_diagram.cells().next().unwrap().points().len(), 3);
+    }
+
+    #[test]
+    fn test_cell_at_returns_nearest_site() {
+        let vor_pts = vec![Point::new(0.0, 1.0), Point::new(2.0, 3.0), Point::new(10.0, 12.0)];
+        let vor_diagram = voronoi(vor_pts, 800.);
+        let cell = vor_diagram.cell_at(Point::new(10.5, 11.5)).unwrap();
+        assert_eq!(cell.site(), Point::new(10.0, 12.0));
+    }
+
+    #[test]
+    fn test_points_discretized_on_segment_site_cell() {
+        let sites = vec![
+            Site::Point(Point::new(15.0, 5.0)),
+            Site::Segment(Segment::new(Point::new(0.0, 0.0), Point::new(0.0, 20.0))),
+        ];
+        let vor_diagram = voronoi_with_sites(sites, 20.0);
+
+        let cell = vor_diagram.cells().next().unwrap();
+        let coarse = cell.points();
+        let fine = cell.points_discretized(0.01);
+
+        // The point site's cell is bounded by a genuine parabolic edge
+        // (focus = the point, directrix = the segment's line), so
+        // discretizing it should add intermediate samples beyond the
+        // polygon's own vertices.
+        assert!(fine.len() > coarse.len());
+    }
+
+    #[test]
+    fn test_delaunay_triangle_references_valid_sites() {
+        // Not cocircular, unlike e.g. (0,0),(4,0),(0,4),(4,4): that's the
+        // textbook degenerate case where all four cells meet at a single
+        // degree-4 Voronoi vertex, which `delaunay()` fan-triangulates.
+        // Here the two triangles come from two separate degree-3 vertices.
+        let vor_pts = vec![Point::new(0.0, 0.0), Point::new(5.0, 0.0), Point::new(1.0, 3.0), Point::new(4.0, 4.0)];
+        let vor_diagram = voronoi(vor_pts, 800.);
+        let triangles = vor_diagram.delaunay();
+
+        assert_eq!(triangles.len(), 2);
+        for triangle in &triangles {
+            for &site in triangle.iter() {
+                assert!(site < 4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_segment_segment_bisector_is_linear() {
+        // Two parallel segments' bisector is the ordinary straight line
+        // midway between them, same as for two points - never a parabola,
+        // which only arises from a point-vs-segment-line pairing.
+        let sites = vec![
+            Site::Segment(Segment::new(Point::new(2.0, 0.0), Point::new(2.0, 10.0))),
+            Site::Segment(Segment::new(Point::new(8.0, 0.0), Point::new(8.0, 10.0))),
+        ];
+        let vor_diagram = voronoi_with_sites(sites, 10.0);
+
+        assert_eq!(vor_diagram.cells().count(), 2);
+        for cell in vor_diagram.cells() {
+            assert_eq!(cell.points_discretized(0.01).len(), cell.points().len());
+        }
+    }
+
+    #[test]
+    fn test_segment_endpoint_region_bisector_is_linear_not_parabolic() {
+        // The point (0, 10) lies beyond the segment's far endpoint (0, 2)
+        // along the segment's own line, so near their shared boundary the
+        // segment's true nearest feature is that endpoint, not its
+        // supporting line: the boundary is an ordinary point-point
+        // bisector, not the parabola a naive point-vs-segment-line
+        // distance (ignoring the segment's own extent) would produce.
+        let sites = vec![
+            Site::Segment(Segment::new(Point::new(0.0, 0.0), Point::new(0.0, 2.0))),
+            Site::Point(Point::new(0.0, 10.0)),
+        ];
+        let vor_diagram = voronoi_with_sites(sites, 20.0);
+
+        assert_eq!(vor_diagram.cells().count(), 2);
+        for cell in vor_diagram.cells() {
+            assert_eq!(cell.points_discretized(0.01).len(), cell.points().len());
+        }
+    }
+
+    #[test]
+    fn test_cell_at_uses_true_segment_distance_not_its_start_point() {
+        let sites = vec![
+            Site::Point(Point::new(5.0, 19.9)),
+            Site::Segment(Segment::new(Point::new(0.0, 0.0), Point::new(0.0, 20.0))),
+        ];
+        let vor_diagram = voronoi_with_sites(sites, 20.0);
+
+        // The true nearest feature of (1, 19.9) is the segment (perp.
+        // dist 1.0), not the point (dist 4.0) - but the segment's `site()`
+        // projects to its far-away start point (0, 0), so a cell_at that
+        // measured distance to `site()` instead of the segment itself
+        // would wrongly prefer the point's cell.
+        let cell = vor_diagram.cell_at(Point::new(1.0, 19.9)).unwrap();
+        assert_eq!(cell.site(), Point::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_find_outside_face_with_a_single_site_spanning_the_whole_box() {
+        // A lone site's cell also touches the box on all four sides, so
+        // its boundary-length sums to the full perimeter too - the exact
+        // case that used to tie with the real outside face under the old
+        // perimeter-coverage heuristic.
+        let vor_diagram = voronoi(vec![Point::new(5.0, 5.0)], 10.0);
+
+        assert_eq!(vor_diagram.cells().count(), 1);
+        let cell = vor_diagram.cells().next().unwrap();
+        assert_eq!(cell.site(), Point::new(5.0, 5.0));
+        assert!((cell.area() - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_find_outside_face_on_collinear_sites() {
+        // Collinear sites produce no interior Voronoi vertices at all
+        // (every bisector is a parallel vertical line), so every cell's
+        // vertices lie on the bounding box - the exact case that used to
+        // defeat the old non-finite-coordinate heuristic.
+        let vor_pts = vec![Point::new(2.0, 5.0), Point::new(5.0, 5.0), Point::new(8.0, 5.0)];
+        let vor_diagram = voronoi(vor_pts, 10.0);
+
+        assert_eq!(vor_diagram.cells().count(), 3);
+
+        let rect = Rect { left: OrderedFloat(0.0), top: OrderedFloat(0.0), right: OrderedFloat(10.0), bottom: OrderedFloat(10.0) };
+        let total_area: f64 = vor_diagram.cells().map(|cell| cell.area_clipped(&rect)).sum();
+        assert!((total_area - 100.0).abs() < 1e-6);
     }
 }
@@ -0,0 +1,130 @@
+use point::Point;
+
+/// Clips `points` against a single convex boundary, via the
+/// Sutherland-Hodgman algorithm. `inside` tests which side of the
+/// boundary a point is on; `intersect` finds where an edge crosses it.
+pub fn clip_edge<F, G>(points: &[Point], inside: F, intersect: G) -> Vec<Point>
+    where F: Fn(Point) -> bool, G: Fn(Point, Point) -> Point
+{
+    if points.is_empty() { return vec![]; }
+
+    let mut output = vec![];
+    let mut prev = points[points.len() - 1];
+    let mut prev_inside = inside(prev);
+
+    for &curr in points {
+        let curr_inside = inside(curr);
+        if curr_inside {
+            if !prev_inside {
+                output.push(intersect(prev, curr));
+            }
+            output.push(curr);
+        } else if prev_inside {
+            output.push(intersect(prev, curr));
+        }
+        prev = curr;
+        prev_inside = curr_inside;
+    }
+
+    output
+}
+
+pub fn distance(a: Point, b: Point) -> f64 {
+    distance_squared(a, b).sqrt()
+}
+
+pub fn distance_squared(a: Point, b: Point) -> f64 {
+    let dx = a.x.into_inner() - b.x.into_inner();
+    let dy = a.y.into_inner() - b.y.into_inner();
+    dx * dx + dy * dy
+}
+
+pub fn intersect_vertical(a: Point, b: Point, x: f64) -> Point {
+    let t = (x - a.x.into_inner()) / (b.x.into_inner() - a.x.into_inner());
+    Point::new(x, a.y.into_inner() + t * (b.y.into_inner() - a.y.into_inner()))
+}
+
+pub fn intersect_horizontal(a: Point, b: Point, y: f64) -> Point {
+    let t = (y - a.y.into_inner()) / (b.y.into_inner() - a.y.into_inner());
+    Point::new(a.x.into_inner() + t * (b.x.into_inner() - a.x.into_inner()), y)
+}
+
+/// Coefficients of a point-to-site squared-distance metric as a quadratic
+/// function of `t` along a segment `a + t * d`: `a2 * t^2 + a1 * t + a0`.
+///
+/// For a point site this is the ordinary squared Euclidean distance; for
+/// a line site (a segment's supporting line) it's the squared
+/// perpendicular distance. Both reduce to a quadratic in `t`, so a
+/// bisector crossing (where two sites' metrics are equal) is always a
+/// quadratic solve, never a special case per site-pair combination.
+#[derive(Debug, Clone, Copy)]
+pub struct MetricCoeffs {
+    pub a2: f64,
+    pub a1: f64,
+    pub a0: f64,
+}
+
+pub fn point_metric_coeffs(a: Point, d: (f64, f64), focus: Point) -> MetricCoeffs {
+    let apx = a.x.into_inner() - focus.x.into_inner();
+    let apy = a.y.into_inner() - focus.y.into_inner();
+    MetricCoeffs {
+        a2: d.0 * d.0 + d.1 * d.1,
+        a1: 2.0 * (apx * d.0 + apy * d.1),
+        a0: apx * apx + apy * apy,
+    }
+}
+
+/// `origin` is any point on the line, `normal` its unit normal (either
+/// orientation works, since only its square is used).
+pub fn line_metric_coeffs(a: Point, d: (f64, f64), origin: Point, normal: (f64, f64)) -> MetricCoeffs {
+    let k = (a.x.into_inner() - origin.x.into_inner()) * normal.0 + (a.y.into_inner() - origin.y.into_inner()) * normal.1;
+    let m = d.0 * normal.0 + d.1 * normal.1;
+    MetricCoeffs {
+        a2: m * m,
+        a1: 2.0 * k * m,
+        a0: k * k,
+    }
+}
+
+/// Returns the real roots of `a2 * t^2 + a1 * t + a0 = 0`.
+pub fn solve_quadratic(a2: f64, a1: f64, a0: f64) -> Vec<f64> {
+    if a2.abs() < 1e-12 {
+        if a1.abs() < 1e-12 { return vec![]; }
+        return vec![-a0 / a1];
+    }
+
+    let discriminant = a1 * a1 - 4.0 * a2 * a0;
+    if discriminant < 0.0 { return vec![]; }
+
+    let sqrt_d = discriminant.sqrt();
+    vec![(-a1 + sqrt_d) / (2.0 * a2), (-a1 - sqrt_d) / (2.0 * a2)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_quadratic_linear_case() {
+        let roots = solve_quadratic(0.0, 2.0, -4.0);
+        assert_eq!(roots, vec![2.0]);
+    }
+
+    #[test]
+    fn test_solve_quadratic_two_roots() {
+        let mut roots = solve_quadratic(1.0, 0.0, -4.0);
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(roots, vec![-2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_clip_edge_against_vertical_line() {
+        let triangle = vec![
+            Point::new(-1.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+        let clipped = clip_edge(&triangle, |p| p.x.into_inner() > 0.0, |a, b| intersect_vertical(a, b, 0.0));
+        assert!(clipped.iter().all(|p| p.x.into_inner() >= 0.0));
+    }
+}
@@ -0,0 +1,14 @@
+extern crate ordered_float;
+
+pub mod point;
+pub mod segment;
+pub mod dcel;
+pub mod geometry;
+pub mod voronoi;
+pub mod diagram;
+pub mod rect;
+pub mod lloyd;
+
+pub use point::Point;
+pub use diagram::{VoronoiDiagram, VoronoiCell};
+pub use voronoi::{voronoi, voronoi_with_sites};
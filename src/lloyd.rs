@@ -0,0 +1,67 @@
+use ordered_float::OrderedFloat;
+
+use point::Point;
+use rect::Rect;
+use voronoi::voronoi;
+
+/// Runs `iterations` rounds of Lloyd's relaxation over `points`, clipping
+/// each cell to the `[0, bounds] x [0, bounds]` box and replacing its site
+/// with the clipped centroid. A site whose cell clips down to nothing, or
+/// that `voronoi()` dropped entirely (e.g. coincident with another site),
+/// is left in place rather than collapsed.
+pub fn relax(points: Vec<Point>, bounds: f64, iterations: usize) -> Vec<Point> {
+    let mut sites = points;
+
+    let rect = Rect {
+        left: OrderedFloat(0.0),
+        top: OrderedFloat(0.0),
+        right: OrderedFloat(bounds),
+        bottom: OrderedFloat(bounds),
+    };
+
+    for _ in 0..iterations {
+        let diagram = voronoi(sites.clone(), bounds);
+
+        sites = sites.iter().enumerate().map(|(i, &original)| {
+            match diagram.cell_for_site(i) {
+                Some(cell) if cell.clipped(&rect).len() >= 3 => cell.centroid_clipped(&rect),
+                _ => original,
+            }
+        }).collect();
+    }
+
+    sites
+}
+
+#[cfg(test)]
+mod tests {
+    use point::Point;
+
+    use super::relax;
+
+    #[test]
+    fn test_relax_pulls_sites_towards_cell_centroids() {
+        // Three points far from the centroid of their own clipped cell;
+        // relaxing should move each one closer to it.
+        let sites = vec![Point::new(1.0, 1.0), Point::new(9.0, 1.0), Point::new(5.0, 9.0)];
+        let relaxed = relax(sites.clone(), 10.0, 1);
+
+        assert_ne!(relaxed, sites);
+        for p in &relaxed {
+            assert!(p.x.into_inner() >= 0.0 && p.x.into_inner() <= 10.0);
+            assert!(p.y.into_inner() >= 0.0 && p.y.into_inner() <= 10.0);
+        }
+    }
+
+    #[test]
+    fn test_relax_keeps_a_site_collapsed_by_a_coincident_duplicate() {
+        // Two coincident sites among three clip each other's cells away to
+        // nothing in voronoi(), so neither has a live face; relax() must
+        // retain all three original sites rather than panicking or
+        // dropping one.
+        let sites = vec![Point::new(5.0, 5.0), Point::new(5.0, 5.0), Point::new(1.0, 1.0)];
+        let relaxed = relax(sites, 10.0, 1);
+
+        assert_eq!(relaxed.len(), 3);
+    }
+}
@@ -0,0 +1,32 @@
+use std::ops::{Add, Mul};
+
+use ordered_float::OrderedFloat;
+
+/// A point in the plane.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: OrderedFloat<f64>,
+    pub y: OrderedFloat<f64>,
+}
+
+impl Point {
+    pub fn new(x: f64, y: f64) -> Point {
+        Point { x: OrderedFloat(x), y: OrderedFloat(y) }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x.into_inner() + other.x.into_inner(), self.y.into_inner() + other.y.into_inner())
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: f64) -> Point {
+        Point::new(self.x.into_inner() * scalar, self.y.into_inner() * scalar)
+    }
+}
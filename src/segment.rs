@@ -0,0 +1,86 @@
+use geometry::distance_squared;
+use point::Point;
+
+/// A line segment site, given by its two endpoints.
+///
+/// Segment sites let `voronoi_with_sites()` compute medial-axis style
+/// diagrams of polylines in addition to the point sites `voronoi()`
+/// already supports. A segment's cell is bounded by the finite segment
+/// itself, not its supporting line: bisectors are parabolic arcs against
+/// a point site while the query projects onto the segment's own span,
+/// straight lines (ordinary point-point bisectors against an endpoint)
+/// beyond either end. For segment-segment pairs the bisector is a
+/// straight line where each side's nearest feature is its own supporting
+/// line, and a parabolic arc where one segment's endpoint faces the other
+/// segment's span.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    pub fn new(start: Point, end: Point) -> Segment {
+        Segment { start, end }
+    }
+
+    /// Returns the squared distance from `p` to the nearest point of this
+    /// segment, clamping the perpendicular projection to `[start, end]`
+    /// rather than extending the segment's supporting line indefinitely.
+    pub fn distance_squared(&self, p: Point) -> f64 {
+        distance_squared(p, self.closest_point(p))
+    }
+
+    /// Returns the point of this segment closest to `p`.
+    pub fn closest_point(&self, p: Point) -> Point {
+        let dx = self.end.x.into_inner() - self.start.x.into_inner();
+        let dy = self.end.y.into_inner() - self.start.y.into_inner();
+        let len_sq = dx * dx + dy * dy;
+
+        let t = if len_sq < 1e-18 {
+            0.0
+        } else {
+            let apx = p.x.into_inner() - self.start.x.into_inner();
+            let apy = p.y.into_inner() - self.start.y.into_inner();
+            ((apx * dx + apy * dy) / len_sq).clamp(0.0, 1.0)
+        };
+
+        Point::new(
+            self.start.x.into_inner() + t * dx,
+            self.start.y.into_inner() + t * dy,
+        )
+    }
+}
+
+/// A Voronoi input site: either a point or a line segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Site {
+    Point(Point),
+    Segment(Segment),
+}
+
+impl Site {
+    /// Returns the squared distance from `p` to the nearest point of this
+    /// site: ordinary Euclidean distance for a point site, and the
+    /// segment's own clamped distance (never its unbounded supporting
+    /// line) for a segment site.
+    pub fn distance_squared(&self, p: Point) -> f64 {
+        match *self {
+            Site::Point(focus) => distance_squared(p, focus),
+            Site::Segment(seg) => seg.distance_squared(p),
+        }
+    }
+}
+
+/// Marks whether a DCEL half-edge is a straight line or a parabolic arc.
+///
+/// Point-point and line-line bisectors are linear; a bisector between a
+/// point (a point site, or a segment's endpoint) and a segment's span is
+/// parabolic, with that point as focus and the segment as directrix, and
+/// linear (an ordinary point-point bisector against the nearer endpoint)
+/// beyond either end of the segment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeType {
+    Linear,
+    Parabolic { focus: Point, directrix: Segment },
+}
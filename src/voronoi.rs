@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use point::Point;
+use segment::{EdgeType, Segment, Site};
+use dcel::{DCEL, Face, HalfEdge};
+use diagram::VoronoiDiagram;
+use geometry::{distance_squared, line_metric_coeffs, point_metric_coeffs, solve_quadratic, MetricCoeffs};
+
+/// Builds the Voronoi diagram of `points`, bounded to a `[0, box_size] x
+/// [0, box_size]` box.
+pub fn voronoi(points: Vec<Point>, box_size: f64) -> VoronoiDiagram {
+    voronoi_with_sites(points.into_iter().map(Site::Point).collect(), box_size)
+}
+
+/// A polygon vertex, paired with the bisector that produced the edge
+/// *incoming* to it (from the previous vertex in winding order): `None`
+/// for an edge that's still on the bounding box, `Some((j, edge_type))`
+/// for an edge on the bisector against site `j`.
+type TaggedPoint = (Point, Option<(usize, EdgeType)>);
+
+/// Builds the Voronoi diagram of a mix of point and segment sites,
+/// bounded to a `[0, box_size] x [0, box_size]` box.
+///
+/// Each cell is computed directly by clipping the bounding box down to
+/// the region closer to its own site than to every other site (by each
+/// site's true clamped distance), via a Sutherland-Hodgman clip. A curved
+/// (parabolic) stretch of boundary is kept as one straight chord between
+/// its two crossing points, tagged `EdgeType::Parabolic`. The clip tags
+/// each resulting edge with
+/// the neighboring site that produced it, so `build_dcel` never needs to
+/// rediscover a shared edge's other side from geometry.
+pub fn voronoi_with_sites(sites: Vec<Site>, box_size: f64) -> VoronoiDiagram {
+    let bounding_box: Vec<TaggedPoint> = vec![
+        (Point::new(0.0, 0.0), None),
+        (Point::new(box_size, 0.0), None),
+        (Point::new(box_size, box_size), None),
+        (Point::new(0.0, box_size), None),
+    ];
+
+    let cell_polygons: Vec<Vec<TaggedPoint>> = (0..sites.len()).map(|i| {
+        let mut polygon = bounding_box.clone();
+        for j in 0..sites.len() {
+            if i == j { continue; }
+            polygon = clip_against_site(&polygon, i, j, &sites);
+            if polygon.len() < 3 { break; }
+        }
+        polygon
+    }).collect();
+
+    build_dcel(sites, cell_polygons, box_size)
+}
+
+/// Clips `polygon` (cell `i`'s partial cell) down to the side closer to
+/// site `i` than to site `j`, tagging the new edge created along the
+/// bisector with `j` and the bisector's `EdgeType`.
+fn clip_against_site(polygon: &[TaggedPoint], i: usize, j: usize, sites: &[Site]) -> Vec<TaggedPoint> {
+    if polygon.is_empty() { return vec![]; }
+
+    let site_i = &sites[i];
+    let site_j = &sites[j];
+    let inside = |p: Point| site_i.distance_squared(p) < site_j.distance_squared(p);
+
+    let mut output = vec![];
+    let mut prev = polygon[polygon.len() - 1];
+    let mut prev_inside = inside(prev.0);
+
+    for &(p, tag) in polygon {
+        let curr_inside = inside(p);
+        if curr_inside {
+            if !prev_inside {
+                let (crossing, edge_type) = boundary_point(prev.0, p, site_i, site_j);
+                output.push((crossing, Some((j, edge_type))));
+            }
+            output.push((p, tag));
+        } else if prev_inside {
+            let (crossing, _) = boundary_point(prev.0, p, site_i, site_j);
+            output.push((crossing, tag));
+        }
+        prev = (p, tag);
+        prev_inside = curr_inside;
+    }
+
+    output
+}
+
+/// One candidate realization of a site's distance metric along a clipped
+/// edge: either the site's own point, or (for a segment, while the query
+/// projects onto its span) its supporting line.
+#[derive(Clone, Copy)]
+enum Feature {
+    Point(Point),
+    Line,
+}
+
+/// Returns every metric a site might realize along edge `a + t * d`: a
+/// point site has exactly one (itself); a segment site has three, since
+/// its true (clamped) distance switches between its supporting line and
+/// either endpoint depending on where the query projects.
+fn metric_variants(a: Point, d: (f64, f64), site: &Site) -> Vec<(MetricCoeffs, Feature)> {
+    match *site {
+        Site::Point(focus) => vec![(point_metric_coeffs(a, d, focus), Feature::Point(focus))],
+        Site::Segment(seg) => {
+            let (origin, normal) = segment_line(seg);
+            vec![
+                (line_metric_coeffs(a, d, origin, normal), Feature::Line),
+                (point_metric_coeffs(a, d, seg.start), Feature::Point(seg.start)),
+                (point_metric_coeffs(a, d, seg.end), Feature::Point(seg.end)),
+            ]
+        }
+    }
+}
+
+/// An arbitrary point on the segment's supporting line, plus its unit
+/// normal (orientation doesn't matter, since only its square is used).
+fn segment_line(seg: Segment) -> (Point, (f64, f64)) {
+    let dx = seg.end.x.into_inner() - seg.start.x.into_inner();
+    let dy = seg.end.y.into_inner() - seg.start.y.into_inner();
+    let len = (dx * dx + dy * dy).sqrt();
+    (seg.start, (-dy / len, dx / len))
+}
+
+/// Finds where the boundary between `site_i` and `site_j` crosses the
+/// segment `a`-`b`, and which kind of bisector it is there.
+///
+/// Each site's true (clamped) distance is piecewise: a segment realizes
+/// either its line or one of its endpoints depending on where the query
+/// falls. So every combination of `site_i`'s and `site_j`'s candidate
+/// metrics is solved in turn, and a candidate root is only accepted once
+/// it's checked against both sites' real `distance_squared` - confirming
+/// the metrics used are the ones actually realized there, not just two
+/// that happen to agree at that `t`.
+fn boundary_point(a: Point, b: Point, site_i: &Site, site_j: &Site) -> (Point, EdgeType) {
+    let d = (b.x.into_inner() - a.x.into_inner(), b.y.into_inner() - a.y.into_inner());
+
+    for (ci, feat_i) in metric_variants(a, d, site_i) {
+        for (cj, feat_j) in metric_variants(a, d, site_j) {
+            for t in solve_quadratic(ci.a2 - cj.a2, ci.a1 - cj.a1, ci.a0 - cj.a0) {
+                if !(-1e-9..=1.0 + 1e-9).contains(&t) { continue; }
+                let t = t.clamp(0.0, 1.0);
+                let point = Point::new(a.x.into_inner() + t * d.0, a.y.into_inner() + t * d.1);
+
+                let di = site_i.distance_squared(point);
+                let dj = site_j.distance_squared(point);
+                if (di - dj).abs() < 1e-6 * di.max(dj).max(1.0) {
+                    return (point, edge_type_for(feat_i, site_i, feat_j, site_j));
+                }
+            }
+        }
+    }
+
+    // `a` and `b` are on opposite sides of the boundary (that's why this
+    // edge is being clipped), so a root in range should always exist;
+    // fall back to the midpoint if floating-point error pushes it just
+    // outside [0, 1] or just past the verification tolerance above.
+    let point = Point::new((a.x.into_inner() + b.x.into_inner()) / 2.0, (a.y.into_inner() + b.y.into_inner()) / 2.0);
+    (point, EdgeType::Linear)
+}
+
+/// A bisector is parabolic exactly when one side realizes a segment's
+/// line (the directrix) and the other a point (the focus); any
+/// point-vs-point pairing, including a segment's own endpoint treated as
+/// a point, is an ordinary straight bisector.
+fn edge_type_for(feat_i: Feature, site_i: &Site, feat_j: Feature, site_j: &Site) -> EdgeType {
+    match (feat_i, feat_j) {
+        (Feature::Line, Feature::Point(focus)) => EdgeType::Parabolic { focus, directrix: as_segment(site_i) },
+        (Feature::Point(focus), Feature::Line) => EdgeType::Parabolic { focus, directrix: as_segment(site_j) },
+        _ => EdgeType::Linear,
+    }
+}
+
+fn as_segment(site: &Site) -> Segment {
+    match *site {
+        Site::Segment(seg) => seg,
+        Site::Point(_) => unreachable!("a Feature::Line can only come from a segment site"),
+    }
+}
+
+const MERGE_EPSILON: f64 = 1e-6;
+
+/// Assembles per-cell polygons into a DCEL: vertices are deduplicated
+/// across cells, each polygon edge already carries (from its clip-time
+/// tag) which other site it borders and what kind of bisector it is, and
+/// the box-boundary edges left over (tag `None`) are stitched into a
+/// single outside face.
+fn build_dcel(sites: Vec<Site>, cell_polygons: Vec<Vec<TaggedPoint>>, box_size: f64) -> VoronoiDiagram {
+    let mut dcel = DCEL::new();
+    let mut vertex_index: HashMap<(i64, i64), usize> = HashMap::new();
+
+    let vertex_of = |dcel: &mut DCEL, vertex_index: &mut HashMap<(i64, i64), usize>, p: Point| -> usize {
+        let key = (
+            (p.x.into_inner() / MERGE_EPSILON).round() as i64,
+            (p.y.into_inner() / MERGE_EPSILON).round() as i64,
+        );
+        if let Some(&i) = vertex_index.get(&key) {
+            return i;
+        }
+        dcel.vertices.push(p);
+        let i = dcel.vertices.len() - 1;
+        vertex_index.insert(key, i);
+        i
+    };
+
+    for (i, site) in sites.iter().enumerate() {
+        let alive = cell_polygons[i].len() >= 3;
+        dcel.faces.push(Face { outer_component: 0, alive, site: *site, is_outside: false });
+    }
+
+    // edge_ids[i] holds the half-edge ids for cell i's polygon, in order,
+    // and neighbor_of_edge[edge_id] the (site, edge_type) its clip-time
+    // tag recorded, if any.
+    let mut edge_ids: Vec<Vec<usize>> = vec![vec![]; sites.len()];
+    let mut neighbor_of_edge: HashMap<usize, (usize, EdgeType)> = HashMap::new();
+
+    for (i, polygon) in cell_polygons.iter().enumerate() {
+        if polygon.len() < 3 { continue; }
+
+        let n = polygon.len();
+        let start = dcel.halfedges.len();
+        for (k, &(p, _)) in polygon.iter().enumerate() {
+            let origin = vertex_of(&mut dcel, &mut vertex_index, p);
+            // Edge `k` runs from vertex `k` to vertex `k + 1`, so its tag
+            // (the bisector that produced it) lives on the *next* vertex,
+            // per `TaggedPoint`'s "tag = incoming edge" convention.
+            let tag = polygon[(k + 1) % n].1;
+            dcel.halfedges.push(HalfEdge {
+                origin,
+                twin: 0,
+                next: start + (k + 1) % n,
+                prev: start + (k + n - 1) % n,
+                face: i,
+                edge_type: tag.map(|(_, edge_type)| edge_type).unwrap_or(EdgeType::Linear),
+            });
+            if let Some(neighbor) = tag {
+                neighbor_of_edge.insert(start + k, neighbor);
+            }
+        }
+        dcel.faces[i].outer_component = start;
+        edge_ids[i] = (start..start + n).collect();
+    }
+
+    let mut matched: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (&edge_id, &(j, _)) in &neighbor_of_edge {
+        let i = dcel.halfedges[edge_id].face;
+        let key = if i < j { (i, j) } else { (j, i) };
+        matched.entry(key).or_default().push(edge_id);
+    }
+
+    let mut box_edges = vec![];
+    for edges in matched.values() {
+        if edges.len() == 2 {
+            dcel.halfedges[edges[0]].twin = edges[1];
+            dcel.halfedges[edges[1]].twin = edges[0];
+        } else {
+            for &e in edges { box_edges.push(e); }
+        }
+    }
+    for &edge_id in edge_ids.iter().flatten() {
+        if !neighbor_of_edge.contains_key(&edge_id) {
+            box_edges.push(edge_id);
+        }
+    }
+
+    stitch_outside_face(&mut dcel, box_edges, box_size);
+
+    VoronoiDiagram::from_dcel(dcel)
+}
+
+/// Creates the outside face and links its half-edges (the twins of the
+/// box-boundary edges left over from `build_dcel`) in order around the
+/// box's perimeter.
+fn stitch_outside_face(dcel: &mut DCEL, box_edges: Vec<usize>, box_size: f64) {
+    if box_edges.is_empty() { return; }
+
+    let outside_face = dcel.faces.len();
+    dcel.faces.push(Face { outer_component: 0, alive: true, site: dcel.faces[0].site, is_outside: true });
+
+    let mut twins = vec![];
+    for &edge_id in &box_edges {
+        let origin = dcel.get_origin(edge_id);
+        let destination = dcel.get_origin(dcel.halfedges[edge_id].next);
+        let twin_origin = vertex_index_of(dcel, destination);
+        let twin = dcel.halfedges.len();
+        dcel.halfedges.push(HalfEdge {
+            origin: twin_origin,
+            twin: edge_id,
+            next: twin,
+            prev: twin,
+            face: outside_face,
+            edge_type: EdgeType::Linear,
+        });
+        dcel.halfedges[edge_id].twin = twin;
+        twins.push((twin, origin));
+    }
+
+    // Sorted by *decreasing* perimeter position, so the outside face winds
+    // clockwise: a half-edge's face is conventionally on its left, and
+    // the real cells' box-boundary edges already wind counterclockwise
+    // (same direction as the box itself), so the outside face needs the
+    // opposite winding for `origin`/`twin`/`prev` vertex circulation
+    // (`edges_around_vertex`) to stay consistent across the boundary.
+    twins.sort_by(|&(_, a), &(_, b)| perimeter_param(b, box_size).partial_cmp(&perimeter_param(a, box_size)).unwrap());
+
+    let n = twins.len();
+    for k in 0..n {
+        let (edge, _) = twins[k];
+        let (next_edge, _) = twins[(k + 1) % n];
+        dcel.halfedges[edge].next = next_edge;
+        dcel.halfedges[next_edge].prev = edge;
+    }
+
+    dcel.faces[outside_face].outer_component = twins[0].0;
+}
+
+fn vertex_index_of(dcel: &DCEL, p: Point) -> usize {
+    dcel.vertices.iter().position(|&v| distance_squared(v, p) < MERGE_EPSILON * MERGE_EPSILON).unwrap()
+}
+
+/// A monotonic parameter increasing counterclockwise around the box
+/// perimeter (the bounding box polygon's own vertex order) starting at the
+/// origin, used to order the outside face's half-edges.
+fn perimeter_param(p: Point, box_size: f64) -> f64 {
+    let x = p.x.into_inner();
+    let y = p.y.into_inner();
+    const BOX_EPSILON: f64 = 1e-6;
+
+    if y.abs() < BOX_EPSILON {
+        x
+    } else if (x - box_size).abs() < BOX_EPSILON {
+        box_size + y
+    } else if (y - box_size).abs() < BOX_EPSILON {
+        2.0 * box_size + (box_size - x)
+    } else {
+        3.0 * box_size + (box_size - y)
+    }
+}